@@ -0,0 +1,143 @@
+//! Batch evaluation harness: plays the entropy solver against many random
+//! answers and reports aggregate statistics.
+
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use rayon::prelude::*;
+use rayon::ThreadPoolBuilder;
+
+use crate::solver::{feedback_pattern, EntropySolver, Solver};
+
+const MAX_ATTEMPTS: usize = 6;
+
+/// Aggregate statistics from a `--bench` run.
+pub struct BenchSummary {
+    games: usize,
+    wins: usize,
+    attempts_histogram: [usize; MAX_ATTEMPTS],
+    failures: usize,
+    worst_attempts: usize,
+    worst_words: Vec<String>,
+}
+
+impl BenchSummary {
+    /// Prints the win rate, average attempts, guess distribution, and
+    /// worst-case words to stdout.
+    pub fn print(&self) {
+        println!("Games played: {}", self.games);
+        println!(
+            "Win rate: {:.2}%",
+            self.wins as f64 / self.games as f64 * 100.0
+        );
+
+        let total_attempts: usize = self
+            .attempts_histogram
+            .iter()
+            .enumerate()
+            .map(|(i, count)| (i + 1) * count)
+            .sum();
+        if self.wins > 0 {
+            println!(
+                "Average attempts (wins): {:.2}",
+                total_attempts as f64 / self.wins as f64
+            );
+        }
+
+        println!("Guess distribution:");
+        for (i, count) in self.attempts_histogram.iter().enumerate() {
+            println!("  {}: {}", i + 1, count);
+        }
+        println!("Failed to solve: {}", self.failures);
+
+        if !self.worst_words.is_empty() {
+            println!(
+                "Worst-case words ({} attempts): {}",
+                self.worst_attempts,
+                self.worst_words.join(", ")
+            );
+        }
+    }
+}
+
+/// Plays the entropy solver against `n` random answers drawn from
+/// `final_words` (seeded for reproducibility) and summarizes the results.
+///
+/// When `threads` is `Some`, a dedicated rayon thread pool of that size runs
+/// the games; otherwise rayon's default global pool is used. Each game is
+/// independent, so the batch runs in parallel.
+pub fn run_bench(
+    acceptable: &[String],
+    final_words: &[String],
+    n: usize,
+    seed: u64,
+    threads: Option<usize>,
+) -> BenchSummary {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let answers: Vec<String> = (0..n)
+        .map(|_| final_words.choose(&mut rng).unwrap().clone())
+        .collect();
+
+    let play_all = || -> Vec<(bool, usize)> {
+        answers
+            .par_iter()
+            .map(|answer| play_one(acceptable, final_words, answer))
+            .collect()
+    };
+
+    let results = match threads {
+        Some(threads) => ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build()
+            .expect("failed to build rayon thread pool")
+            .install(play_all),
+        None => play_all(),
+    };
+
+    let mut summary = BenchSummary {
+        games: n,
+        wins: 0,
+        attempts_histogram: [0; MAX_ATTEMPTS],
+        failures: 0,
+        worst_attempts: 0,
+        worst_words: Vec::new(),
+    };
+
+    for (answer, (won, attempts)) in answers.iter().zip(results.iter()) {
+        if *won {
+            summary.wins += 1;
+            summary.attempts_histogram[*attempts - 1] += 1;
+            if *attempts > summary.worst_attempts {
+                summary.worst_attempts = *attempts;
+                summary.worst_words.clear();
+                summary.worst_words.push(answer.clone());
+            } else if *attempts == summary.worst_attempts {
+                summary.worst_words.push(answer.clone());
+            }
+        } else {
+            summary.failures += 1;
+        }
+    }
+
+    summary
+}
+
+/// Plays a single game of the solver against `answer`, returning whether it
+/// won and how many attempts it took (or `MAX_ATTEMPTS` on failure).
+fn play_one(acceptable: &[String], final_words: &[String], answer: &str) -> (bool, usize) {
+    let mut solver = EntropySolver::new(acceptable, final_words);
+
+    for attempt in 1..=MAX_ATTEMPTS {
+        let guess = match solver.suggest() {
+            Some(guess) => guess,
+            None => return (false, attempt),
+        };
+        if guess == answer {
+            return (true, attempt);
+        }
+        let pattern = feedback_pattern(&guess, answer);
+        solver.update(&guess, &pattern);
+    }
+
+    (false, MAX_ATTEMPTS)
+}
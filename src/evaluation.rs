@@ -0,0 +1,156 @@
+//! A first-class representation of a guess's feedback, replacing the
+//! ad-hoc, ANSI-styled `String` that `provide_feedback` used to produce.
+
+use console::style;
+use std::fmt;
+
+/// The result of comparing a single guessed letter against the answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The letter is in the correct position (green).
+    Matched,
+    /// The letter is in the answer, but in a different position (yellow).
+    Exists,
+    /// The letter is not in the answer, accounting for duplicates already
+    /// matched elsewhere (red).
+    None,
+}
+
+impl Status {
+    /// The `GYR` character used in the plain-text feedback encoding.
+    fn code(self) -> char {
+        match self {
+            Status::Matched => 'G',
+            Status::Exists => 'Y',
+            Status::None => 'R',
+        }
+    }
+
+    fn from_code(code: char) -> Self {
+        match code {
+            'G' => Status::Matched,
+            'Y' => Status::Exists,
+            'R' => Status::None,
+            other => panic!("invalid feedback code '{}', expected one of G, Y, R", other),
+        }
+    }
+}
+
+/// The per-letter feedback for a guess against an answer.
+pub struct Evaluation {
+    pub cells: Vec<(char, Status)>,
+}
+
+impl Evaluation {
+    /// Computes the feedback for `guess` against `answer` using the
+    /// two-pass (green-then-yellow, with consumed answer slots) matching
+    /// rule: a letter is only `Exists` if an unmatched copy of it remains
+    /// in the answer after all exact matches are removed.
+    pub fn build(guess: &str, answer: &str) -> Self {
+        let guess_chars: Vec<char> = guess.chars().collect();
+        let mut answer_chars: Vec<char> = answer.chars().collect();
+        let mut statuses = vec![Status::None; guess_chars.len()];
+
+        for (i, &c) in guess_chars.iter().enumerate() {
+            if answer_chars[i] == c {
+                statuses[i] = Status::Matched;
+                answer_chars[i] = '_';
+            }
+        }
+
+        for (i, &c) in guess_chars.iter().enumerate() {
+            if statuses[i] == Status::None {
+                if let Some(pos) = answer_chars.iter().position(|&x| x == c) {
+                    statuses[i] = Status::Exists;
+                    answer_chars[pos] = '_';
+                }
+            }
+        }
+
+        Evaluation {
+            cells: guess_chars.into_iter().zip(statuses).collect(),
+        }
+    }
+
+    /// Reconstructs an `Evaluation` from a `guess` and the `GYR` pattern
+    /// string it was reported to have produced (e.g. from an external
+    /// game, or a persisted solver history).
+    pub fn from_pattern(guess: &str, pattern: &str) -> Self {
+        assert_eq!(
+            guess.chars().count(),
+            pattern.chars().count(),
+            "guess and pattern must have the same length"
+        );
+        Evaluation {
+            cells: guess
+                .chars()
+                .zip(pattern.chars().map(Status::from_code))
+                .collect(),
+        }
+    }
+
+    /// The `GYR` encoding of this evaluation.
+    pub fn pattern(&self) -> String {
+        self.cells.iter().map(|(_, status)| status.code()).collect()
+    }
+}
+
+impl fmt::Display for Evaluation {
+    /// Renders each letter in its matched/exists/none color. `console`
+    /// only emits ANSI escapes when stdout is a tty, so this is plain text
+    /// when piped or redirected.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (c, status) in &self.cells {
+            let styled = match status {
+                Status::Matched => style(c).green(),
+                Status::Exists => style(c).yellow(),
+                Status::None => style(c).red(),
+            };
+            write!(f, "{}", styled)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn build_handles_duplicate_letters() {
+        // ALLEY vs LEVEL: both guessed L's are yellow (the answer has two
+        // L's, neither consumed by an exact match), the E lands green, and
+        // the non-repeated A/Y are red.
+        let evaluation = Evaluation::build("ALLEY", "LEVEL");
+        assert_eq!(evaluation.pattern(), "RYYGR");
+        assert_eq!(
+            evaluation.cells,
+            vec![
+                ('A', Status::None),
+                ('L', Status::Exists),
+                ('L', Status::Exists),
+                ('E', Status::Matched),
+                ('Y', Status::None),
+            ]
+        );
+    }
+
+    #[test]
+    fn build_only_marks_as_many_duplicates_as_the_answer_has() {
+        // SASSY vs CHESS: the answer has two S's. One is matched exactly
+        // (position 3, green); of the two leftover guessed S's, only the
+        // first (position 0) gets a yellow, the other (position 2) is red
+        // because no unmatched S remains in the answer.
+        let evaluation = Evaluation::build("SASSY", "CHESS");
+        assert_eq!(evaluation.pattern(), "YRRGR");
+    }
+
+    #[test]
+    fn from_pattern_round_trips_through_pattern() {
+        let original = Evaluation::build("ALLEY", "LEVEL");
+        let pattern = original.pattern();
+        let reconstructed = Evaluation::from_pattern("ALLEY", &pattern);
+        assert_eq!(reconstructed.pattern(), pattern);
+        assert_eq!(reconstructed.cells, original.cells);
+    }
+}
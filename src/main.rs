@@ -1,4 +1,4 @@
-use clap::{Arg, Command};
+use clap::{Arg, ArgAction, Command};
 use console::style;
 use std::collections::{HashMap, HashSet};
 use std::io::{self, Write};
@@ -11,7 +11,22 @@ use std::fs::{self, File};
 use std::io::Read;
 
 mod builtin_words;
-use builtin_words::{FINAL, ACCEPTABLE};
+
+mod evaluation;
+use evaluation::{Evaluation, Status};
+
+mod solver;
+use solver::{EntropySolver, Solver};
+
+mod bench;
+
+mod repl;
+
+mod wordlist;
+use wordlist::WordList;
+
+mod practice;
+use practice::WordSchedule;
 
 #[derive(Serialize, Deserialize)]
 struct GameState {
@@ -19,6 +34,8 @@ struct GameState {
     successful_games: usize,
     attempts: usize,
     used_words: HashMap<String, usize>,
+    #[serde(default)]
+    word_schedules: HashMap<String, WordSchedule>,
 }
 
 impl GameState {
@@ -28,6 +45,7 @@ impl GameState {
             successful_games: 0,
             attempts: 0,
             used_words: HashMap::new(),
+            word_schedules: HashMap::new(),
         }
     }
 
@@ -90,8 +108,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                 .short('s')
                 .long("seed")
                 .num_args(1)
-                .requires("random")
-                .help("Specify the seed for random word generation"),
+                .help("Specify the seed for random word generation (used by --random and --bench)"),
         )
         .arg(
             Arg::new("difficult")
@@ -107,8 +124,83 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                 .num_args(1)
                 .help("Specify the state file for saving/loading game state"),
         )
+        .arg(
+            Arg::new("solve")
+                .long("solve")
+                .num_args(0)
+                .action(ArgAction::SetTrue)
+                .help("Let the entropy solver play the game by itself"),
+        )
+        .arg(
+            Arg::new("bench")
+                .long("bench")
+                .num_args(1)
+                .help("Run the solver against N random FINAL answers and report statistics"),
+        )
+        .arg(
+            Arg::new("threads")
+                .long("threads")
+                .num_args(1)
+                .requires("bench")
+                .help("Number of threads to use for --bench (defaults to rayon's global pool)"),
+        )
+        .arg(
+            Arg::new("repl")
+                .long("repl")
+                .num_args(0)
+                .action(ArgAction::SetTrue)
+                .help("Interactively solve a Wordle puzzle being played elsewhere"),
+        )
+        .arg(
+            Arg::new("final-set")
+                .long("final-set")
+                .num_args(1)
+                .help("Load the answer word list from a newline-delimited file instead of the builtin list"),
+        )
+        .arg(
+            Arg::new("acceptable-set")
+                .long("acceptable-set")
+                .num_args(1)
+                .help("Load the acceptable-guess word list from a newline-delimited file instead of the builtin list"),
+        )
+        .arg(
+            Arg::new("practice")
+                .long("practice")
+                .num_args(0)
+                .action(ArgAction::SetTrue)
+                .help("Practice mode: resurface due/difficult words via a spaced-repetition schedule"),
+        )
         .get_matches();
 
+    // Prepare the ACCEPTABLE and FINAL word lists, falling back to the builtins
+    let word_list = WordList::load(
+        matches.get_one::<String>("final-set").map(String::as_str),
+        matches.get_one::<String>("acceptable-set").map(String::as_str),
+    )?;
+    let acceptable_words = word_list.acceptable_words();
+    let final_words = word_list.final_words();
+    let acceptable_set = word_list.acceptable;
+    let final_set = word_list.final_set;
+    let solve_mode = matches.get_flag("solve");
+
+    if let Some(n) = matches.get_one::<String>("bench") {
+        let n: usize = n.parse().expect("--bench expects a positive integer");
+        let seed = matches
+            .get_one::<String>("seed")
+            .map_or_else(|| rand::thread_rng().gen::<u64>(), |s| s.parse().unwrap_or_else(|_| rand::thread_rng().gen::<u64>()));
+        let threads = matches
+            .get_one::<String>("threads")
+            .map(|t| t.parse().expect("--threads expects a positive integer"));
+        println!("Debug: Running benchmark over {} games with seed {}", n, seed);
+        let summary = bench::run_bench(&acceptable_words, &final_words, n, seed, threads);
+        summary.print();
+        return Ok(());
+    }
+
+    if matches.get_flag("repl") {
+        return repl::run(&acceptable_words, &final_words).map_err(Into::into);
+    }
+
     let is_tty = atty::is(atty::Stream::Stdout);
 
     if is_tty {
@@ -129,10 +221,6 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let name = line.trim().to_string();
     println!("Debug: Welcome to wordle, {}!", name);
 
-    // Prepare the ACCEPTABLE list for case-insensitive comparison
-    let acceptable_set: HashSet<String> = ACCEPTABLE.iter().map(|&word| word.to_uppercase()).collect();
-    let final_set: HashSet<String> = FINAL.iter().map(|&word| word.to_uppercase()).collect();
-
     // Debug: Print the lengths of the lists
     println!("Debug: ACCEPTABLE list length: {}", acceptable_set.len());
     println!("Debug: FINAL list length: {}", final_set.len());
@@ -145,7 +233,13 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         game_state.total_rounds += 1;
 
         // Determine the answer word
-        let answer = if matches.contains_id("random") {
+        let answer = if matches.get_flag("practice") {
+            // Practice mode: the word most overdue in the spaced-repetition schedule
+            println!("Debug: Practice mode enabled");
+            let word = practice::pick_due_word(&final_words, &game_state.word_schedules).to_string();
+            println!("Debug: Practice word selected: {}", word);
+            word
+        } else if matches.contains_id("random") {
             // Random mode
             println!("Debug: Random mode enabled");
             let seed = matches.get_one::<String>("seed")
@@ -162,7 +256,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                 });
             println!("Debug: Seed used: {}", seed);
             let mut rng = StdRng::seed_from_u64(seed);
-            let random_word = FINAL.choose(&mut rng).unwrap().to_string().to_uppercase();
+            let random_word = final_words.choose(&mut rng).unwrap().clone();
             println!("Debug: Random word selected: {}", random_word);
             random_word
         } else if let Some(word) = matches.get_one::<String>("word") {
@@ -197,6 +291,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
         // Game loop
         let mut attempts = 0;
+        let mut won = false;
         const MAX_ATTEMPTS: usize = 6;
 
         // Debug: Indicate entering the game loop
@@ -204,12 +299,41 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
         let mut correct_positions = vec!['_'; 5];
         let mut present_letters = HashSet::new();
+        let mut solver_state = EntropySolver::new(&acceptable_words, &final_words);
 
         while attempts < MAX_ATTEMPTS {
-            println!("Attempt {}: Enter your guess:", attempts + 1);
-            let mut guess = String::new();
-            io::stdin().read_line(&mut guess)?;
-            let guess = guess.trim().to_uppercase();
+            let guess = if solve_mode {
+                // The answer is only checked against ACCEPTABLE, so a word outside
+                // FINAL can leave the solver with no candidate.
+                let guess = match solver_state.suggest() {
+                    Some(guess) => guess,
+                    None => {
+                        println!("Solver: no FINAL word is consistent with the feedback so far.");
+                        break;
+                    }
+                };
+                println!("Attempt {}: Solver guesses: {}", attempts + 1, guess);
+                guess
+            } else {
+                println!("Attempt {}: Enter your guess (or \"hint\"):", attempts + 1);
+                let mut guess = String::new();
+                io::stdin().read_line(&mut guess)?;
+                let guess = guess.trim().to_uppercase();
+
+                if guess == "HINT" {
+                    match solver_state.suggest() {
+                        Some(hint) => println!(
+                            "Hint: try '{}' ({} candidates remain)",
+                            hint,
+                            solver_state.candidates().len()
+                        ),
+                        None => println!("Hint: no candidate word is consistent with the feedback so far."),
+                    }
+                    continue;
+                }
+
+                guess
+            };
 
             // Debug: Show the entered guess
             println!("Debug: Guess entered: {}", guess);
@@ -226,8 +350,9 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             }
 
             // Provide feedback for the guess
-            let feedback = provide_feedback(&guess, &answer, is_tty);
-            println!("Feedback: {}", feedback);
+            let evaluation = Evaluation::build(&guess, &answer);
+            println!("Feedback: {}", evaluation);
+            solver_state.update(&guess, &evaluation.pattern());
 
             attempts += 1;
             game_state.attempts += 1;
@@ -236,16 +361,19 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             if guess == answer {
                 println!("Congratulations! You've guessed the word.");
                 game_state.successful_games += 1;
+                won = true;
                 break;
             }
 
             // Update correct positions and present letters for hard mode
             if hard_mode {
-                for (i, c) in guess.chars().enumerate() {
-                    if answer.chars().nth(i) == Some(c) {
-                        correct_positions[i] = c;
-                    } else if answer.contains(c) {
-                        present_letters.insert(c);
+                for (i, (c, status)) in evaluation.cells.iter().enumerate() {
+                    match status {
+                        Status::Matched => correct_positions[i] = *c,
+                        Status::Exists => {
+                            present_letters.insert(*c);
+                        }
+                        Status::None => {}
                     }
                 }
             }
@@ -255,6 +383,13 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             }
         }
 
+        // Update the word's spaced-repetition schedule for practice mode
+        game_state
+            .word_schedules
+            .entry(answer.clone())
+            .or_default()
+            .record(attempts, won, practice::now());
+
         println!("Do you want to play another round? (y/n)");
         let mut play_again = String::new();
         io::stdin().read_line(&mut play_again)?;
@@ -272,6 +407,11 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+// `correct_positions`/`present_letters` are the cumulative projection of
+// every `Evaluation` seen so far this round (matched letters by position,
+// and existing letters), not a single `Evaluation` itself — the validator
+// checks a new guess against the whole history of past feedback, so it
+// keeps this folded shape rather than taking an `Evaluation`.
 fn is_valid_hard_mode_guess(guess: &str, correct_positions: &[char], present_letters: &HashSet<char>) -> bool {
     for (i, c) in guess.chars().enumerate() {
         if correct_positions[i] != '_' && correct_positions[i] != c {
@@ -288,45 +428,3 @@ fn is_valid_hard_mode_guess(guess: &str, correct_positions: &[char], present_let
     true
 }
 
-fn provide_feedback(guess: &str, answer: &str, is_tty: bool) -> String {
-    let mut feedback = String::new();
-    let mut answer_chars: Vec<char> = answer.chars().collect();
-    let guess_chars: Vec<char> = guess.chars().collect();
-
-    // First pass: Check for correct positions (green)
-    for (i, c) in guess_chars.iter().enumerate() {
-        if answer_chars[i] == *c {
-            if is_tty {
-                feedback.push_str(&format!("{}", style(c).green()));
-            } else {
-                feedback.push('G');
-            }
-            answer_chars[i] = '_'; // Mark this character as matched
-        } else {
-            feedback.push('_'); // Placeholder for second pass
-        }
-    }
-
-    // Second pass: Check for correct letters in wrong positions (yellow)
-    for (i, c) in guess_chars.iter().enumerate() {
-        if feedback.chars().nth(i) == Some('_') {
-            if answer_chars.contains(c) {
-                if is_tty {
-                    feedback.replace_range(i..=i, &format!("{}", style(c).yellow()));
-                } else {
-                    feedback.replace_range(i..=i, "Y");
-                }
-                let pos = answer_chars.iter().position(|&x| x == *c).unwrap();
-                answer_chars[pos] = '_'; // Mark this character as matched
-            } else {
-                if is_tty {
-                    feedback.replace_range(i..=i, &format!("{}", style(c).red()));
-                } else {
-                    feedback.replace_range(i..=i, "R");
-                }
-            }
-        }
-    }
-
-    feedback
-}
@@ -0,0 +1,186 @@
+//! Spaced-repetition scheduling for `--practice` mode: resurfaces missed or
+//! difficult answer words using an SM-2-style schedule instead of picking a
+//! random word every round.
+
+use std::collections::HashMap;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+const SECONDS_PER_DAY: u64 = 86_400;
+
+/// The SM-2 schedule for a single answer word, persisted across sessions.
+#[derive(Serialize, Deserialize, Clone)]
+pub struct WordSchedule {
+    /// Guesses taken to solve the word the last time it was played (or the
+    /// attempt limit, on a loss).
+    pub attempts: usize,
+    /// Unix timestamp (seconds) the word was last played.
+    pub last_seen: u64,
+    /// SM-2 ease factor, starting at 2.5 and never dropping below 1.3.
+    pub ease: f64,
+    /// Days until the word is due again.
+    pub interval: u32,
+    /// Consecutive rounds in a row solved with quality >= 3.
+    pub repetitions: u32,
+}
+
+impl Default for WordSchedule {
+    fn default() -> Self {
+        WordSchedule {
+            attempts: 0,
+            last_seen: 0,
+            ease: 2.5,
+            interval: 0,
+            repetitions: 0,
+        }
+    }
+}
+
+impl WordSchedule {
+    /// Updates the schedule after a round played with this word as the
+    /// answer: `attempts` guesses were used, and `won` records whether the
+    /// player solved it. A win in 1-2 guesses is treated as high quality
+    /// recall (q=5); a loss is the lowest quality (q=0).
+    pub fn record(&mut self, attempts: usize, won: bool, now: u64) {
+        let quality: i32 = if !won {
+            0
+        } else {
+            match attempts {
+                0..=2 => 5,
+                3 => 4,
+                4 => 3,
+                5 => 2,
+                _ => 1,
+            }
+        };
+
+        let q = quality as f64;
+        self.ease = (self.ease + (0.1 - (5.0 - q) * (0.08 + (5.0 - q) * 0.02))).max(1.3);
+
+        if quality < 3 {
+            self.repetitions = 0;
+            self.interval = 1;
+        } else {
+            self.repetitions += 1;
+            self.interval = match self.repetitions {
+                1 => 1,
+                2 => 6,
+                _ => (self.interval as f64 * self.ease).round() as u32,
+            };
+        }
+
+        self.attempts = attempts;
+        self.last_seen = now;
+    }
+
+    /// The unix timestamp (seconds) at which this word becomes due again.
+    fn due_at(&self) -> u64 {
+        self.last_seen + self.interval as u64 * SECONDS_PER_DAY
+    }
+}
+
+/// The current unix timestamp, in seconds.
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("system clock is before the unix epoch")
+        .as_secs()
+}
+
+/// Picks the most overdue word in `final_words` according to `schedules`.
+/// Words with no schedule yet (never played) are due immediately.
+pub fn pick_due_word<'a>(
+    final_words: &'a [String],
+    schedules: &HashMap<String, WordSchedule>,
+) -> &'a str {
+    final_words
+        .iter()
+        .min_by_key(|word| schedules.get(*word).map(WordSchedule::due_at).unwrap_or(0))
+        .expect("final word list must not be empty")
+        .as_str()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approx_eq(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-9
+    }
+
+    #[test]
+    fn good_recalls_grow_the_interval_and_ease() {
+        let mut schedule = WordSchedule::default();
+        assert!(approx_eq(schedule.ease, 2.5));
+
+        // First good recall (win in 2 guesses, q=5): ease +0.1, interval -> 1.
+        schedule.record(2, true, 1_000);
+        assert!(approx_eq(schedule.ease, 2.6));
+        assert_eq!(schedule.repetitions, 1);
+        assert_eq!(schedule.interval, 1);
+
+        // Second good recall (win in 3 guesses, q=4): interval becomes 6.
+        schedule.record(3, true, 2_000);
+        assert!(approx_eq(schedule.ease, 2.6));
+        assert_eq!(schedule.repetitions, 2);
+        assert_eq!(schedule.interval, 6);
+
+        // Third good recall (win in 1 guess, q=5): interval = round(6 * 2.7).
+        schedule.record(1, true, 3_000);
+        assert!(approx_eq(schedule.ease, 2.7));
+        assert_eq!(schedule.repetitions, 3);
+        assert_eq!(schedule.interval, 16);
+    }
+
+    #[test]
+    fn a_poor_recall_resets_the_interval_and_floors_the_ease() {
+        let mut schedule = WordSchedule {
+            attempts: 1,
+            last_seen: 3_000,
+            ease: 1.35,
+            interval: 16,
+            repetitions: 3,
+        };
+
+        // A loss (q=0) resets repetitions/interval, and should floor ease at 1.3.
+        schedule.record(6, false, 4_000);
+        assert!(approx_eq(schedule.ease, 1.3));
+        assert_eq!(schedule.repetitions, 0);
+        assert_eq!(schedule.interval, 1);
+        assert_eq!(schedule.last_seen, 4_000);
+    }
+
+    #[test]
+    fn pick_due_word_prefers_never_seen_then_most_overdue() {
+        let final_words = vec!["ABBEY".to_string(), "CRANE".to_string()];
+        let mut schedules = HashMap::new();
+        schedules.insert(
+            "ABBEY".to_string(),
+            WordSchedule {
+                attempts: 2,
+                last_seen: 10,
+                interval: 1,
+                ease: 2.6,
+                repetitions: 1,
+            },
+        );
+
+        // CRANE has never been played, so it's due immediately (due_at = 0).
+        assert_eq!(pick_due_word(&final_words, &schedules), "CRANE");
+
+        // Once both have been played, the one with the earlier due date wins:
+        // ABBEY is due at 10 + 1 day, CRANE at 1_000_000 + 1 day.
+        schedules.insert(
+            "CRANE".to_string(),
+            WordSchedule {
+                attempts: 3,
+                last_seen: 1_000_000,
+                interval: 1,
+                ease: 2.6,
+                repetitions: 1,
+            },
+        );
+        assert_eq!(pick_due_word(&final_words, &schedules), "ABBEY");
+    }
+}
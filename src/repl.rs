@@ -0,0 +1,97 @@
+//! An interactive REPL for solving a Wordle puzzle being played elsewhere
+//! (e.g. the official NYT game), decoupled from this crate's own answer
+//! generation.
+
+use std::io::{self, Write};
+
+use crate::evaluation::Evaluation;
+use crate::solver::{EntropySolver, Solver};
+
+/// Runs the REPL: each turn the user reports the guess they made and the
+/// `GYR` feedback they received, and the REPL prints the recommended next
+/// guess plus how many candidate answers remain. Typing `undo` reverts the
+/// last entry.
+pub fn run(acceptable: &[String], final_words: &[String]) -> io::Result<()> {
+    let mut history: Vec<(String, String)> = Vec::new();
+    let mut solver = EntropySolver::new(acceptable, final_words);
+
+    println!("Enter \"<guess> <pattern>\", where pattern is a 5-letter string of G/Y/R (e.g. \"CRANE GYRRR\").");
+    println!("Type \"undo\" to revert the last entry.");
+    suggest(&solver);
+
+    loop {
+        print!("> ");
+        io::stdout().flush()?;
+
+        let mut line = String::new();
+        if io::stdin().read_line(&mut line)? == 0 {
+            break;
+        }
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        if line.eq_ignore_ascii_case("undo") {
+            if history.pop().is_none() {
+                println!("Nothing to undo.");
+                continue;
+            }
+            solver = replay(acceptable, final_words, &history);
+            println!("Undid last entry.");
+            suggest(&solver);
+            continue;
+        }
+
+        let mut parts = line.split_whitespace();
+        let (guess, pattern) = match (parts.next(), parts.next()) {
+            (Some(guess), Some(pattern)) => (guess.to_uppercase(), pattern.to_uppercase()),
+            _ => {
+                println!("Expected \"<guess> <pattern>\", e.g. \"CRANE GYRRR\".");
+                continue;
+            }
+        };
+
+        if pattern.len() != guess.len() || !pattern.chars().all(|c| matches!(c, 'G' | 'Y' | 'R')) {
+            println!("Pattern must be a {}-letter string over G, Y, R.", guess.len());
+            continue;
+        }
+
+        let evaluation = Evaluation::from_pattern(&guess, &pattern);
+
+        let mut candidate_solver = replay(acceptable, final_words, &history);
+        candidate_solver.update(&guess, &evaluation.pattern());
+
+        if candidate_solver.candidates().is_empty() {
+            println!(
+                "That feedback is inconsistent with every remaining candidate; ignoring it. \
+                 Double-check the guess and pattern, or \"undo\" a previous entry."
+            );
+            continue;
+        }
+
+        history.push((guess, evaluation.pattern()));
+        solver = candidate_solver;
+        suggest(&solver);
+    }
+
+    Ok(())
+}
+
+/// Rebuilds a solver from scratch and replays `history` against it, since
+/// `EntropySolver` only prunes candidates forward.
+fn replay(acceptable: &[String], final_words: &[String], history: &[(String, String)]) -> EntropySolver {
+    let mut solver = EntropySolver::new(acceptable, final_words);
+    for (guess, pattern) in history {
+        solver.update(guess, pattern);
+    }
+    solver
+}
+
+fn suggest(solver: &EntropySolver) {
+    println!("{} candidates remain.", solver.candidates().len());
+    match solver.suggest() {
+        Some(guess) => println!("Suggested next guess: {}", guess),
+        None => println!("No candidates remain; the feedback history may be inconsistent."),
+    }
+}
@@ -0,0 +1,127 @@
+//! Automated solver subsystem.
+//!
+//! `EntropySolver` picks the guess that maximizes the expected information
+//! gain (Shannon entropy) over the feedback pattern it could produce against
+//! the remaining candidate answers.
+
+use crate::evaluation::Evaluation;
+
+/// Computes the G/Y/R feedback pattern for `guess` against `answer`, using
+/// the same two-pass matching as `Evaluation::build` so candidate pruning
+/// stays consistent with the feedback the game actually reports.
+pub fn feedback_pattern(guess: &str, answer: &str) -> String {
+    Evaluation::build(guess, answer).pattern()
+}
+
+/// A strategy that, given the history of guesses and feedback so far,
+/// suggests the next guess to make.
+pub trait Solver {
+    /// Returns the next guess to try, or `None` if no candidate remains.
+    fn suggest(&self) -> Option<String>;
+
+    /// Narrows the candidate set using the feedback received for `guess`.
+    fn update(&mut self, guess: &str, pattern: &str);
+
+    /// The answers still consistent with every feedback seen so far.
+    fn candidates(&self) -> &[String];
+}
+
+/// A solver that always guesses the word maximizing the Shannon entropy of
+/// the feedback pattern it would produce against the remaining candidates.
+pub struct EntropySolver {
+    allowed: Vec<String>,
+    candidates: Vec<String>,
+}
+
+impl EntropySolver {
+    /// Builds a solver over `acceptable` allowed guesses and `final_words`
+    /// as the initial candidate set of possible answers.
+    pub fn new(acceptable: &[String], final_words: &[String]) -> Self {
+        EntropySolver {
+            allowed: acceptable.to_vec(),
+            candidates: final_words.to_vec(),
+        }
+    }
+
+    /// Scores `guess` by the Shannon entropy of the feedback-pattern
+    /// distribution it induces over the current candidate set.
+    fn entropy(&self, guess: &str) -> f64 {
+        let mut buckets: std::collections::HashMap<String, usize> = std::collections::HashMap::new();
+        for candidate in &self.candidates {
+            let pattern = feedback_pattern(guess, candidate);
+            *buckets.entry(pattern).or_insert(0) += 1;
+        }
+
+        let total = self.candidates.len() as f64;
+        buckets
+            .values()
+            .map(|&count| {
+                let p = count as f64 / total;
+                -p * p.log2()
+            })
+            .sum()
+    }
+}
+
+impl Solver for EntropySolver {
+    fn suggest(&self) -> Option<String> {
+        if self.candidates.is_empty() {
+            return None;
+        }
+        if self.candidates.len() == 1 {
+            return Some(self.candidates[0].clone());
+        }
+
+        let candidate_set: std::collections::HashSet<&str> =
+            self.candidates.iter().map(String::as_str).collect();
+
+        self.allowed
+            .iter()
+            .map(|word| (word, self.entropy(word)))
+            .max_by(|(word_a, entropy_a), (word_b, entropy_b)| {
+                entropy_a
+                    .partial_cmp(entropy_b)
+                    .unwrap()
+                    .then_with(|| {
+                        candidate_set
+                            .contains(word_a.as_str())
+                            .cmp(&candidate_set.contains(word_b.as_str()))
+                    })
+            })
+            .map(|(word, _)| word.clone())
+    }
+
+    fn update(&mut self, guess: &str, pattern: &str) {
+        self.candidates
+            .retain(|candidate| feedback_pattern(guess, candidate) == pattern);
+    }
+
+    fn candidates(&self) -> &[String] {
+        &self.candidates
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn feedback_pattern_handles_duplicate_letters() {
+        // Same duplicate-letter case as `Evaluation::build`'s tests: only as
+        // many guessed S's turn yellow as the answer has left unmatched.
+        assert_eq!(feedback_pattern("SASSY", "CHESS"), "YRRGR");
+    }
+
+    #[test]
+    fn update_narrows_candidates_to_those_consistent_with_the_pattern() {
+        let acceptable = vec!["CRANE".to_string(), "SLATE".to_string()];
+        let final_words = vec!["CRANE".to_string(), "CRONE".to_string(), "SLATE".to_string()];
+        let mut solver = EntropySolver::new(&acceptable, &final_words);
+
+        let pattern = feedback_pattern("CRANE", "CRONE");
+        solver.update("CRANE", &pattern);
+
+        assert_eq!(solver.candidates(), &["CRONE".to_string()]);
+        assert_eq!(solver.suggest(), Some("CRONE".to_string()));
+    }
+}
@@ -0,0 +1,76 @@
+//! Pluggable word lists: the acceptable-guess and valid-answer sets can be
+//! loaded from newline-delimited files instead of only the builtin arrays,
+//! which is what lets `--bench` (or regular play) target non-English or
+//! custom-length variants.
+
+use std::collections::HashSet;
+use std::fs;
+use std::io;
+
+use crate::builtin_words::{ACCEPTABLE, FINAL};
+
+/// The acceptable-guess set and the valid-answer (FINAL) set for a game.
+pub struct WordList {
+    pub acceptable: HashSet<String>,
+    pub final_set: HashSet<String>,
+}
+
+impl WordList {
+    /// Loads the acceptable and final word sets.
+    ///
+    /// When a path is given, the corresponding file is read as one
+    /// uppercase word per line; otherwise the builtin list is used. Every
+    /// word in the final set must also be present in the acceptable set.
+    pub fn load(final_path: Option<&str>, acceptable_path: Option<&str>) -> io::Result<Self> {
+        let acceptable = match acceptable_path {
+            Some(path) => read_word_file(path)?,
+            None => ACCEPTABLE.iter().map(|&word| word.to_uppercase()).collect(),
+        };
+        let final_set = match final_path {
+            Some(path) => read_word_file(path)?,
+            None => FINAL.iter().map(|&word| word.to_uppercase()).collect(),
+        };
+
+        if let Some(missing) = final_set.iter().find(|word| !acceptable.contains(*word)) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "answer word '{}' from the final set is not in the acceptable set",
+                    missing
+                ),
+            ));
+        }
+
+        Ok(WordList { acceptable, final_set })
+    }
+
+    /// The acceptable set as a sorted `Vec`, for callers (like the solver)
+    /// that want an indexable word list rather than a set. Sorted so that
+    /// word order is stable across runs: `HashSet`'s iteration order is
+    /// randomized per-process, which would otherwise make anything that
+    /// indexes into this list by position (e.g. `--bench --seed`) silently
+    /// non-reproducible.
+    pub fn acceptable_words(&self) -> Vec<String> {
+        let mut words: Vec<String> = self.acceptable.iter().cloned().collect();
+        words.sort();
+        words
+    }
+
+    /// The final set as a sorted `Vec`; see `acceptable_words` for why the
+    /// order must be stable.
+    pub fn final_words(&self) -> Vec<String> {
+        let mut words: Vec<String> = self.final_set.iter().cloned().collect();
+        words.sort();
+        words
+    }
+}
+
+fn read_word_file(path: &str) -> io::Result<HashSet<String>> {
+    let contents = fs::read_to_string(path)?;
+    Ok(contents
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(str::to_uppercase)
+        .collect())
+}